@@ -0,0 +1,76 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! API trait of the transaction RPC-V2 spec.
+
+use crate::transaction::event::{TransactionEvent, ValidatedTransaction};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_core::Bytes;
+
+/// Transaction RPC-V2 API.
+#[rpc(client, server)]
+pub trait TransactionApi<Hash> {
+	/// Submit an extrinsic to watch.
+	///
+	/// See [`TransactionEvent`] for details on the subscription events.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[subscription(
+		name = "transaction_unstable_submitAndWatch" => "transaction_unstable_watchEvent",
+		unsubscribe = "transaction_unstable_unwatch",
+		item = TransactionEvent<Hash>,
+	)]
+	fn submit_and_watch(&self, bytes: Bytes) -> SubscriptionResult;
+
+	/// Validate an extrinsic at a given block without submitting it to the pool.
+	///
+	/// When `at` is not provided, the current best block is used.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "transaction_unstable_validateTransaction")]
+	fn validate_transaction(
+		&self,
+		bytes: Bytes,
+		at: Option<Hash>,
+	) -> RpcResult<ValidatedTransaction>;
+
+	/// Submit an extrinsic for background propagation to peers.
+	///
+	/// Unlike [`Self::submit_and_watch`], the extrinsic's lifetime is not tied to the
+	/// subscription's connection: the node keeps broadcasting it until [`Self::stop_broadcast`]
+	/// is called with the returned operation id, the pool stops watching it (typically once it
+	/// is finalized), the node considers the extrinsic invalid, or the pool rejects a submission.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "transaction_unstable_broadcast")]
+	fn broadcast(&self, bytes: Bytes) -> RpcResult<String>;
+
+	/// Stop a background propagation previously started by [`Self::broadcast`].
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "transaction_unstable_stop")]
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()>;
+}
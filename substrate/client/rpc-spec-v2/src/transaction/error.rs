@@ -0,0 +1,32 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Errors of the transaction RPC-V2 API.
+
+use sc_transaction_pool_api::error::Error as PoolError;
+
+/// Transaction RPC errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// The transaction pool rejected the extrinsic.
+	#[error("Transaction pool error: {0}")]
+	Pool(#[from] PoolError),
+	/// The extrinsic watcher could not be created for a reason unrelated to pool validation.
+	#[error("Extrinsic verification error: {0}")]
+	Verification(Box<dyn std::error::Error + Send + Sync>),
+}
@@ -0,0 +1,123 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Events and result types of the transaction RPC-V2 API.
+
+use crate::transaction::error::Error;
+use sc_transaction_pool_api::error::Error as PoolError;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// The transaction was included in a block of a chain.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionBlock<Hash> {
+	/// The hash of the block the transaction was included in.
+	pub hash: Hash,
+	/// The index of the transaction within the block.
+	pub index: u32,
+	/// The extrinsic's dispatch outcome, when it could be determined from the block's
+	/// `System::Events`.
+	///
+	/// `None` when no dispatch outcome provider is registered, or when the block's events could
+	/// not be read or hold no outcome for this extrinsic.
+	pub dispatch_outcome: Option<DispatchOutcome>,
+}
+
+/// The dispatch outcome of an extrinsic that has been included in a block.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DispatchOutcome {
+	/// True if `frame_system` recorded an `ExtrinsicSuccess` event for this extrinsic.
+	pub success: bool,
+	/// The SCALE-encoded `DispatchError`, present only when `success` is `false`.
+	pub error: Option<Bytes>,
+}
+
+/// The transaction was broadcasted to a number of peers.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionBroadcasted {
+	/// The number of peers the transaction has been broadcasted to.
+	pub num_peers: usize,
+}
+
+/// The transaction could not be processed and was dropped from the pool.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionDropped {
+	/// True if the transaction was broadcasted to at least one peer before being dropped.
+	pub broadcasted: bool,
+	/// The reason why the transaction was dropped.
+	pub error: String,
+}
+
+/// The transaction was deemed invalid by the pool.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionError {
+	/// The reason why the transaction is invalid.
+	pub error: String,
+}
+
+/// The events generated while tracking a submitted transaction.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum TransactionEvent<Hash> {
+	/// The transaction is part of the ready or future queue.
+	Validated,
+	/// The transaction has been broadcasted to the provided number of peers.
+	Broadcasted(TransactionBroadcasted),
+	/// The transaction has been included in a block of the best chain, or retracted from one.
+	BestChainBlockIncluded(Option<TransactionBlock<Hash>>),
+	/// The transaction has been included in a finalized block.
+	Finalized(TransactionBlock<Hash>),
+	/// The transaction was deemed invalid.
+	Invalid(TransactionError),
+	/// The transaction was dropped from the pool.
+	Dropped(TransactionDropped),
+}
+
+impl<Hash> From<Error> for TransactionEvent<Hash> {
+	fn from(e: Error) -> Self {
+		match e {
+			Error::Pool(PoolError::InvalidTransaction(error)) =>
+				TransactionEvent::Invalid(TransactionError { error: format!("{:?}", error) }),
+			Error::Pool(PoolError::UnknownTransaction(error)) =>
+				TransactionEvent::Invalid(TransactionError { error: format!("{:?}", error) }),
+			Error::Pool(error) => TransactionEvent::Dropped(TransactionDropped {
+				broadcasted: false,
+				error: error.to_string(),
+			}),
+			Error::Verification(error) =>
+				TransactionEvent::Invalid(TransactionError { error: error.to_string() }),
+		}
+	}
+}
+
+/// The result of validating an extrinsic without submitting it to the pool.
+///
+/// Mirrors the fields of `sp_runtime::transaction_validity::ValidTransaction`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ValidatedTransaction {
+	/// Priority of the transaction, used to order it against other ready transactions.
+	pub priority: u64,
+	/// Tags this transaction requires in order to be included in a block.
+	pub requires: Vec<Bytes>,
+	/// Tags this transaction provides once included in a block.
+	pub provides: Vec<Bytes>,
+	/// Number of blocks for which the transaction is guaranteed to be valid.
+	pub longevity: u64,
+	/// Whether the transaction should be gossiped to other peers.
+	pub propagate: bool,
+}
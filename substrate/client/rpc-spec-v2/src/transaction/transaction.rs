@@ -23,13 +23,14 @@ use crate::{
 		api::TransactionApiServer,
 		error::Error,
 		event::{
-			TransactionBlock, TransactionBroadcasted, TransactionDropped, TransactionError,
-			TransactionEvent,
+			DispatchOutcome, TransactionBlock, TransactionBroadcasted, TransactionDropped,
+			TransactionError, TransactionEvent, ValidatedTransaction,
 		},
 	},
 	SubscriptionTaskExecutor,
 };
 use jsonrpsee::{
+	core::RpcResult,
 	types::{
 		error::{CallError, ErrorObject},
 		SubscriptionResult,
@@ -40,30 +41,125 @@ use sc_transaction_pool_api::{
 	error::IntoPoolError, BlockHash, TransactionFor, TransactionPool, TransactionSource,
 	TransactionStatus,
 };
-use std::sync::Arc;
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+};
 
+use frame_system::{EventRecord, Phase};
+use sc_client_api::{Backend, StorageProvider};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_core::Bytes;
-use sp_runtime::traits::Block as BlockT;
+use sp_core::{twox_128, Bytes};
+use sp_runtime::{
+	traits::Block as BlockT,
+	transaction_validity::{TransactionValidityError, ValidTransaction},
+	DispatchError,
+};
+use sp_storage::StorageKey;
+use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
 
-use codec::Decode;
-use futures::{FutureExt, StreamExt, TryFutureExt};
+use codec::{Decode, Encode};
+use futures::{channel::oneshot, future::AbortHandle, FutureExt, StreamExt, TryFutureExt};
 
 /// An API for transaction RPC calls.
-pub struct Transaction<Pool, Client> {
+pub struct Transaction<Pool: TransactionPool, Client> {
 	/// Substrate client.
 	client: Arc<Client>,
 	/// Transactions pool.
 	pool: Arc<Pool>,
 	/// Executor to spawn subscriptions.
 	executor: SubscriptionTaskExecutor,
+	/// Handles of the background broadcast tasks started by [`Transaction::broadcast`].
+	broadcast_handles: Arc<BroadcastHandles>,
+	/// Optional provider of extrinsic dispatch outcomes, registered via
+	/// [`Transaction::with_dispatch_outcome_provider`].
+	dispatch_outcome: Option<Arc<dyn ExtrinsicDispatchOutcome<BlockHash<Pool>> + Send + Sync>>,
 }
 
-impl<Pool, Client> Transaction<Pool, Client> {
+/// Tracks the background broadcast tasks started by [`Transaction::broadcast`], keyed by a
+/// monotonically increasing operation id.
+#[derive(Default)]
+struct BroadcastHandles {
+	/// Counter used to generate unique broadcast operation ids.
+	next_operation_id: AtomicU64,
+	handles: Mutex<HashMap<String, AbortHandle>>,
+}
+
+impl BroadcastHandles {
+	/// Generates a fresh, unique operation id.
+	fn next_operation_id(&self) -> String {
+		self.next_operation_id.fetch_add(1, Ordering::Relaxed).to_string()
+	}
+
+	/// Registers `handle` under `operation_id`.
+	fn insert(&self, operation_id: String, handle: AbortHandle) {
+		self.handles
+			.lock()
+			.expect("broadcast_handles lock is never held across an await point; qed")
+			.insert(operation_id, handle);
+	}
+
+	/// Removes `operation_id`, if present, without aborting it.
+	///
+	/// Used once a broadcast reaches a terminal state on its own, so its id doesn't linger in
+	/// the map forever; see [`Transaction::broadcast_loop`].
+	fn remove(&self, operation_id: &str) {
+		self.handles
+			.lock()
+			.expect("broadcast_handles lock is never held across an await point; qed")
+			.remove(operation_id);
+	}
+
+	/// Aborts and removes the broadcast registered under `operation_id`, or an
+	/// [`UNKNOWN_OPERATION_ID`] error if none exists.
+	fn stop(&self, operation_id: &str) -> RpcResult<()> {
+		match self
+			.handles
+			.lock()
+			.expect("broadcast_handles lock is never held across an await point; qed")
+			.remove(operation_id)
+		{
+			Some(handle) => {
+				handle.abort();
+				Ok(())
+			},
+			None => Err(CallError::Custom(ErrorObject::owned(
+				UNKNOWN_OPERATION_ID,
+				format!("Operation id {:?} not found", operation_id),
+				None::<()>,
+			))
+			.into()),
+		}
+	}
+}
+
+impl<Pool: TransactionPool, Client> Transaction<Pool, Client> {
 	/// Creates a new [`Transaction`].
 	pub fn new(client: Arc<Client>, pool: Arc<Pool>, executor: SubscriptionTaskExecutor) -> Self {
-		Transaction { client, pool, executor }
+		Transaction {
+			client,
+			pool,
+			executor,
+			broadcast_handles: Default::default(),
+			dispatch_outcome: None,
+		}
+	}
+
+	/// Registers a provider able to determine whether an already-included extrinsic
+	/// succeeded, by decoding the runtime's concrete aggregate event type.
+	///
+	/// Without one registered, `InBlock`/`Finalized` events are still emitted, just without a
+	/// `dispatch_outcome`.
+	pub fn with_dispatch_outcome_provider(
+		mut self,
+		provider: Arc<dyn ExtrinsicDispatchOutcome<BlockHash<Pool>> + Send + Sync>,
+	) -> Self {
+		self.dispatch_outcome = Some(provider);
+		self
 	}
 }
 
@@ -81,12 +177,78 @@ const TX_SOURCE: TransactionSource = TransactionSource::External;
 /// This is similar to the old `author` API error code.
 const BAD_FORMAT: i32 = 1001;
 
+/// The extrinsic was deemed invalid by the `TaggedTransactionQueue` runtime API.
+const INVALID_TRANSACTION: i32 = 1002;
+
+/// The extrinsic's validity could not be established by the `TaggedTransactionQueue` runtime
+/// API.
+const UNKNOWN_TRANSACTION: i32 = 1003;
+
+/// The `TaggedTransactionQueue::validate_transaction` runtime API call itself failed (e.g. a
+/// panic or other execution error), as distinct from the extrinsic being deemed invalid.
+const RUNTIME_ERROR: i32 = 1004;
+
+/// No broadcast operation exists for the given operation id.
+const UNKNOWN_OPERATION_ID: i32 = 1005;
+
+/// Builds the [`BAD_FORMAT`] error for an extrinsic that failed to decode.
+fn bad_format_error(e: impl std::fmt::Display) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		BAD_FORMAT,
+		format!("Extrinsic has invalid format: {}", e),
+		None::<()>,
+	))
+}
+
+/// Builds the [`RUNTIME_ERROR`] error for a failed `TaggedTransactionQueue::validate_transaction`
+/// runtime API call, as distinct from the extrinsic being deemed invalid.
+fn runtime_error(e: impl std::fmt::Display) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		format!("Unable to dry-run extrinsic: {}", e),
+		None::<()>,
+	))
+}
+
+/// Converts the result of `TaggedTransactionQueue::validate_transaction` into the RPC response,
+/// mapping `Invalid`/`Unknown` validity errors to their respective error codes.
+fn validated_transaction_response(
+	validity: Result<ValidTransaction, TransactionValidityError>,
+) -> RpcResult<ValidatedTransaction> {
+	match validity {
+		Ok(valid) => Ok(ValidatedTransaction {
+			priority: valid.priority,
+			requires: valid.requires.into_iter().map(Into::into).collect(),
+			provides: valid.provides.into_iter().map(Into::into).collect(),
+			longevity: valid.longevity,
+			propagate: valid.propagate,
+		}),
+		Err(TransactionValidityError::Invalid(invalid)) => Err(CallError::Custom(
+			ErrorObject::owned(
+				INVALID_TRANSACTION,
+				"Invalid Transaction".to_string(),
+				Some(format!("{:?}", invalid)),
+			),
+		)
+		.into()),
+		Err(TransactionValidityError::Unknown(unknown)) => Err(CallError::Custom(
+			ErrorObject::owned(
+				UNKNOWN_TRANSACTION,
+				"Unknown Transaction Validity".to_string(),
+				Some(format!("{:?}", unknown)),
+			),
+		)
+		.into()),
+	}
+}
+
 impl<Pool, Client> TransactionApiServer<BlockHash<Pool>> for Transaction<Pool, Client>
 where
 	Pool: TransactionPool + Sync + Send + 'static,
 	Pool::Hash: Unpin,
 	<Pool::Block as BlockT>::Hash: Unpin,
 	Client: HeaderBackend<Pool::Block> + ProvideRuntimeApi<Pool::Block> + Send + Sync + 'static,
+	Client::Api: TaggedTransactionQueue<Pool::Block>,
 {
 	fn submit_and_watch(&self, mut sink: SubscriptionSink, xt: Bytes) -> SubscriptionResult {
 		// This is the only place where the RPC server can return an error for this
@@ -94,17 +256,14 @@ where
 		let decoded_extrinsic = match TransactionFor::<Pool>::decode(&mut &xt[..]) {
 			Ok(decoded_extrinsic) => decoded_extrinsic,
 			Err(e) => {
-				let err = CallError::Custom(ErrorObject::owned(
-					BAD_FORMAT,
-					format!("Extrinsic has invalid format: {}", e),
-					None::<()>,
-				));
-				let _ = sink.reject(err);
+				let _ = sink.reject(bad_format_error(e));
 				return Ok(())
 			},
 		};
 
 		let best_block_hash = self.client.info().best_hash;
+		let dispatch_outcome = self.dispatch_outcome.clone();
+		let executor = self.executor.clone();
 
 		let submit = self
 			.pool
@@ -118,9 +277,35 @@ where
 		let fut = async move {
 			match submit.await {
 				Ok(stream) => {
-					let mut state = TransactionState::new();
-					let stream =
-						stream.filter_map(|event| async move { state.handle_event(event) });
+					let state = TransactionState::new();
+					let stream = stream
+						.scan(state, |state, event| {
+							futures::future::ready(Some(state.handle_event(event)))
+						})
+						.filter_map(move |outcome| {
+							let dispatch_outcome = dispatch_outcome.clone();
+							let executor = executor.clone();
+							async move {
+								match outcome {
+									EventOutcome::Skip => None,
+									EventOutcome::Ready(event) => Some(event),
+									EventOutcome::PendingDispatchOutcome {
+										hash,
+										index,
+										finalized,
+									} => Some(
+										included_event(
+											&executor,
+											dispatch_outcome,
+											hash,
+											index,
+											finalized,
+										)
+										.await,
+									),
+								}
+							}
+						});
 					sink.pipe_from_stream(stream.boxed()).await;
 				},
 				Err(err) => {
@@ -135,6 +320,291 @@ where
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 		Ok(())
 	}
+
+	fn validate_transaction(
+		&self,
+		xt: Bytes,
+		at: Option<BlockHash<Pool>>,
+	) -> RpcResult<ValidatedTransaction> {
+		// This is decoded the same way as `submit_and_watch`: a bad format never reaches
+		// the runtime API.
+		let decoded_extrinsic =
+			TransactionFor::<Pool>::decode(&mut &xt[..]).map_err(bad_format_error)?;
+
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let validity = self
+			.client
+			.runtime_api()
+			.validate_transaction(at, TX_SOURCE, decoded_extrinsic, at)
+			.map_err(runtime_error)?;
+
+		validated_transaction_response(validity)
+	}
+
+	fn broadcast(&self, xt: Bytes) -> RpcResult<String> {
+		let decoded_extrinsic =
+			TransactionFor::<Pool>::decode(&mut &xt[..]).map_err(bad_format_error)?;
+
+		let operation_id = self.broadcast_handles.next_operation_id();
+
+		let (fut, abort_handle) = futures::future::abortable(Self::broadcast_loop(
+			self.pool.clone(),
+			self.client.clone(),
+			decoded_extrinsic,
+			operation_id.clone(),
+			self.broadcast_handles.clone(),
+		));
+
+		self.broadcast_handles.insert(operation_id.clone(), abort_handle);
+
+		self.executor.spawn(
+			"substrate-rpc-transaction-broadcast",
+			Some("rpc"),
+			fut.map(drop).boxed(),
+		);
+
+		Ok(operation_id)
+	}
+
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()> {
+		self.broadcast_handles.stop(&operation_id)
+	}
+}
+
+impl<Pool, Client> Transaction<Pool, Client>
+where
+	Pool: TransactionPool + Sync + Send + 'static,
+	<Pool::Block as BlockT>::Hash: Unpin,
+	Client: HeaderBackend<Pool::Block> + Send + Sync + 'static,
+{
+	/// Runs [`Self::broadcast_until_done`] to completion, then removes `operation_id` from
+	/// `handles`.
+	///
+	/// This is the counterpart of the removal [`Transaction::stop_broadcast`] performs when the
+	/// caller aborts the broadcast itself: without it, operation ids for transactions that reach
+	/// a terminal state on their own (finalized, invalid, or an error on the initial submit)
+	/// would never be cleared from the map.
+	async fn broadcast_loop(
+		pool: Arc<Pool>,
+		client: Arc<Client>,
+		xt: TransactionFor<Pool>,
+		operation_id: String,
+		handles: Arc<BroadcastHandles>,
+	) {
+		Self::broadcast_until_done(pool, client, xt).await;
+
+		handles.remove(&operation_id);
+	}
+
+	/// Keep (re-)submitting `xt` to `pool` for as long as possible in the background.
+	///
+	/// This watches past inclusion in a block: it only stops once the watcher stream itself
+	/// ends (typically once the transaction is finalized), the transaction is deemed invalid, the
+	/// pool rejects a (re-)submission, or its task is aborted via [`Transaction::stop_broadcast`].
+	///
+	/// A transaction can be evicted from the pool (e.g. `Usurped` or `Dropped`, which commonly
+	/// happen across a reorg) without actually being invalid; in that case it is simply
+	/// resubmitted so that the background propagation keeps going.
+	async fn broadcast_until_done(pool: Arc<Pool>, client: Arc<Client>, xt: TransactionFor<Pool>) {
+		loop {
+			let best_block_hash = client.info().best_hash;
+			let mut watcher =
+				match pool.submit_and_watch(best_block_hash, TX_SOURCE, xt.clone()).await {
+					Ok(watcher) => watcher.fuse(),
+					Err(_) => return,
+				};
+
+			let mut resubmit = false;
+			while let Some(status) = watcher.next().await {
+				match classify_broadcast_status(&status) {
+					BroadcastStep::Resubmit => {
+						resubmit = true;
+						break
+					},
+					BroadcastStep::Stop => return,
+					BroadcastStep::Continue => {},
+				}
+			}
+
+			if !resubmit {
+				return
+			}
+		}
+	}
+}
+
+/// What [`Transaction::broadcast_until_done`] should do in response to a single pool event for
+/// the transaction it's watching.
+#[derive(Debug, PartialEq)]
+enum BroadcastStep {
+	/// Keep watching; this event doesn't change anything for the background broadcast.
+	Continue,
+	/// The transaction was evicted from the pool without being deemed invalid; resubmit it.
+	Resubmit,
+	/// The transaction was deemed invalid; stop broadcasting it.
+	Stop,
+}
+
+/// Classifies a single pool event for [`Transaction::broadcast_until_done`].
+fn classify_broadcast_status<Hash, BlockHash>(
+	status: &TransactionStatus<Hash, BlockHash>,
+) -> BroadcastStep {
+	match status {
+		TransactionStatus::Usurped(_) | TransactionStatus::Dropped => BroadcastStep::Resubmit,
+		TransactionStatus::Invalid => BroadcastStep::Stop,
+		_ => BroadcastStep::Continue,
+	}
+}
+
+/// Determines whether an already-included extrinsic succeeded.
+///
+/// [`Transaction`] is generic over the runtime, so it has no way to decode the runtime's
+/// aggregate `RuntimeEvent` type itself. [`SystemEventsDispatchOutcome`] provides a generic
+/// implementation for any client exposing `System::Events` storage, parameterised over the
+/// concrete `RuntimeEvent`; it is the implementation nodes are expected to register with
+/// [`Transaction::with_dispatch_outcome_provider`].
+pub trait ExtrinsicDispatchOutcome<Hash>: Send + Sync {
+	/// Returns the dispatch outcome of the extrinsic at `index` in block `at`, or `None` when
+	/// it could not be determined (e.g. the block's events could not be retrieved).
+	fn extrinsic_dispatch_outcome(&self, at: Hash, index: u32) -> Option<DispatchOutcome>;
+}
+
+/// Lets [`SystemEventsDispatchOutcome`] recognise a runtime's own
+/// `frame_system::Event::{ExtrinsicSuccess, ExtrinsicFailed}` variants within its aggregate
+/// `RuntimeEvent` type.
+///
+/// A runtime's generated `RuntimeEvent` implements this by matching on its `System` variant,
+/// e.g. `RuntimeEvent::System(frame_system::Event::ExtrinsicSuccess { .. }) => Some(Ok(()))`.
+pub trait SystemExtrinsicOutcome {
+	/// Returns this event's outcome if it is a `frame_system::Event::ExtrinsicSuccess` or
+	/// `ExtrinsicFailed`, `None` for any other event.
+	fn as_extrinsic_outcome(&self) -> Option<Result<(), DispatchError>>;
+}
+
+/// Reads an extrinsic's dispatch outcome out of a client's `System::Events` storage item.
+///
+/// Generic over the concrete `RuntimeEvent` so it can be constructed and registered with
+/// [`Transaction::with_dispatch_outcome_provider`] without this crate depending on any
+/// particular runtime.
+pub struct SystemEventsDispatchOutcome<Client, Block, B, RuntimeEvent> {
+	client: Arc<Client>,
+	_marker: std::marker::PhantomData<(Block, B, RuntimeEvent)>,
+}
+
+impl<Client, Block, B, RuntimeEvent> SystemEventsDispatchOutcome<Client, Block, B, RuntimeEvent> {
+	/// Construct a new [`SystemEventsDispatchOutcome`] reading `System::Events` through `client`.
+	pub fn new(client: Arc<Client>) -> Self {
+		SystemEventsDispatchOutcome { client, _marker: Default::default() }
+	}
+}
+
+impl<Client, Block, B, RuntimeEvent> ExtrinsicDispatchOutcome<Block::Hash>
+	for SystemEventsDispatchOutcome<Client, Block, B, RuntimeEvent>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+	Client: StorageProvider<Block, B> + Send + Sync,
+	RuntimeEvent: Decode + SystemExtrinsicOutcome + Send + Sync,
+{
+	fn extrinsic_dispatch_outcome(&self, at: Block::Hash, index: u32) -> Option<DispatchOutcome> {
+		let key = {
+			let mut key = twox_128(b"System").to_vec();
+			key.extend_from_slice(&twox_128(b"Events"));
+			StorageKey(key)
+		};
+
+		let raw_events = self.client.storage(at, &key).ok()??;
+		let events =
+			<Vec<EventRecord<RuntimeEvent, Block::Hash>>>::decode(&mut &raw_events.0[..]).ok()?;
+
+		events.into_iter().find_map(|record| {
+			if record.phase != Phase::ApplyExtrinsic(index) {
+				return None
+			}
+			record.event.as_extrinsic_outcome().map(|outcome| DispatchOutcome {
+				success: outcome.is_ok(),
+				error: outcome.err().map(|e| Bytes(e.encode())),
+			})
+		})
+	}
+}
+
+/// Discriminant of the last event emitted to the subscriber, used to detect
+/// semantically identical events that should be coalesced.
+#[derive(Clone, Copy, PartialEq)]
+enum LastEvent {
+	/// The last emitted event was [`TransactionEvent::Validated`].
+	Validated,
+	/// The last emitted event was [`TransactionEvent::Broadcasted`].
+	Broadcasted,
+	/// The last emitted event was none of the above.
+	Other,
+}
+
+/// What [`TransactionState::handle_event`] wants forwarded to the subscriber for a single
+/// pool event.
+#[derive(Debug, PartialEq)]
+enum EventOutcome<BlockHash> {
+	/// The event is coalesced into a previously emitted one; nothing to forward.
+	Skip,
+	/// Forward this event to the subscriber as-is.
+	Ready(TransactionEvent<BlockHash>),
+	/// Forward a `BestChainBlockIncluded` (`finalized: false`) or `Finalized`
+	/// (`finalized: true`) event once the extrinsic's dispatch outcome at `(hash, index)` has
+	/// been looked up. Kept separate from [`Self::Ready`] so the lookup, which can block on a
+	/// storage read, happens off the stream's polling path; see [`resolve_dispatch_outcome`].
+	PendingDispatchOutcome { hash: BlockHash, index: u32, finalized: bool },
+}
+
+/// Looks up `provider`'s dispatch outcome for the extrinsic at `index` in block `hash`,
+/// running the (potentially blocking) lookup on `executor`'s blocking thread pool rather than
+/// the task polling the subscription stream.
+async fn resolve_dispatch_outcome<BlockHash>(
+	executor: &SubscriptionTaskExecutor,
+	provider: Option<Arc<dyn ExtrinsicDispatchOutcome<BlockHash> + Send + Sync>>,
+	hash: BlockHash,
+	index: u32,
+) -> Option<DispatchOutcome>
+where
+	BlockHash: Send + 'static,
+{
+	let provider = provider?;
+	let (tx, rx) = oneshot::channel();
+
+	executor.spawn_blocking(
+		"substrate-rpc-transaction-dispatch-outcome",
+		Some("rpc"),
+		async move {
+			let _ = tx.send(provider.extrinsic_dispatch_outcome(hash, index));
+		}
+		.boxed(),
+	);
+
+	rx.await.ok().flatten()
+}
+
+/// Builds the `BestChainBlockIncluded` (`finalized: false`) or `Finalized` (`finalized: true`)
+/// event for the extrinsic at `index` in block `hash`, resolving its dispatch outcome via
+/// [`resolve_dispatch_outcome`] first.
+async fn included_event<BlockHash>(
+	executor: &SubscriptionTaskExecutor,
+	dispatch_outcome: Option<Arc<dyn ExtrinsicDispatchOutcome<BlockHash> + Send + Sync>>,
+	hash: BlockHash,
+	index: u32,
+	finalized: bool,
+) -> TransactionEvent<BlockHash>
+where
+	BlockHash: Clone + Send + 'static,
+{
+	let dispatch_outcome =
+		resolve_dispatch_outcome(executor, dispatch_outcome, hash.clone(), index).await;
+	let block = TransactionBlock { hash, index, dispatch_outcome };
+	if finalized {
+		TransactionEvent::Finalized(block)
+	} else {
+		TransactionEvent::BestChainBlockIncluded(Some(block))
+	}
 }
 
 /// The transaction's state that needs to be preserved between
@@ -142,18 +612,24 @@ where
 ///
 /// # Note
 ///
-/// In the future, the RPC server can submit only the last event when multiple
-/// identical events happen in a row.
-#[derive(Clone, Copy)]
+/// The RPC server submits only the last event when multiple identical events happen in a
+/// row: repeated `Ready`/`Future` transitions collapse into a single `Validated` event, and a
+/// `Broadcast` is only reported again once it reaches more peers than the last one reported.
+/// State-changing transitions (inclusion, retraction, finalization, and the terminal
+/// invalid/dropped events) are always forwarded.
 struct TransactionState {
 	/// True if the transaction was previously broadcasted.
 	broadcasted: bool,
+	/// The number of peers reported by the last emitted [`TransactionEvent::Broadcasted`].
+	last_broadcasted_peers: usize,
+	/// Discriminant of the last event emitted to the subscriber.
+	last_event: Option<LastEvent>,
 }
 
 impl TransactionState {
 	/// Construct a new [`TransactionState`].
 	pub fn new() -> Self {
-		TransactionState { broadcasted: false }
+		TransactionState { broadcasted: false, last_broadcasted_peers: 0, last_event: None }
 	}
 
 	/// Handle events generated by the transaction-pool and convert them
@@ -162,41 +638,341 @@ impl TransactionState {
 	pub fn handle_event<Hash: Clone, BlockHash: Clone>(
 		&mut self,
 		event: TransactionStatus<Hash, BlockHash>,
-	) -> Option<TransactionEvent<BlockHash>> {
+	) -> EventOutcome<BlockHash> {
 		match event {
-			TransactionStatus::Ready | TransactionStatus::Future =>
-				Some(TransactionEvent::<BlockHash>::Validated),
+			TransactionStatus::Ready | TransactionStatus::Future => {
+				if self.last_event == Some(LastEvent::Validated) {
+					return EventOutcome::Skip
+				}
+				self.last_event = Some(LastEvent::Validated);
+				EventOutcome::Ready(TransactionEvent::<BlockHash>::Validated)
+			},
 			TransactionStatus::Broadcast(peers) => {
 				// Set the broadcasted flag once if we submitted the transaction to
 				// at least one peer.
 				self.broadcasted = self.broadcasted || !peers.is_empty();
 
-				Some(TransactionEvent::Broadcasted(TransactionBroadcasted {
+				// Only report the broadcast again once it reaches more peers than the last
+				// one we told the subscriber about.
+				if peers.len() <= self.last_broadcasted_peers {
+					return EventOutcome::Skip
+				}
+				self.last_broadcasted_peers = peers.len();
+				self.last_event = Some(LastEvent::Broadcasted);
+
+				EventOutcome::Ready(TransactionEvent::Broadcasted(TransactionBroadcasted {
 					num_peers: peers.len(),
 				}))
 			},
-			TransactionStatus::InBlock((hash, index)) =>
-				Some(TransactionEvent::BestChainBlockIncluded(Some(TransactionBlock {
-					hash,
-					index,
-				}))),
-			TransactionStatus::Retracted(_) => Some(TransactionEvent::BestChainBlockIncluded(None)),
-			TransactionStatus::FinalityTimeout(_) =>
-				Some(TransactionEvent::Dropped(TransactionDropped {
+			TransactionStatus::InBlock((hash, index)) => {
+				self.last_event = Some(LastEvent::Other);
+				EventOutcome::PendingDispatchOutcome { hash, index, finalized: false }
+			},
+			TransactionStatus::Retracted(_) => {
+				self.last_event = Some(LastEvent::Other);
+				EventOutcome::Ready(TransactionEvent::BestChainBlockIncluded(None))
+			},
+			TransactionStatus::FinalityTimeout(_) => {
+				self.last_event = Some(LastEvent::Other);
+				EventOutcome::Ready(TransactionEvent::Dropped(TransactionDropped {
 					broadcasted: self.broadcasted,
 					error: "Maximum number of finality watchers has been reached".into(),
+				}))
+			},
+			TransactionStatus::Finalized((hash, index)) => {
+				self.last_event = Some(LastEvent::Other);
+				EventOutcome::PendingDispatchOutcome { hash, index, finalized: true }
+			},
+			TransactionStatus::Usurped(_) =>
+				EventOutcome::Ready(TransactionEvent::Invalid(TransactionError {
+					error: "Extrinsic was rendered invalid by another extrinsic".into(),
+				})),
+			TransactionStatus::Dropped =>
+				EventOutcome::Ready(TransactionEvent::Invalid(TransactionError {
+					error: "Extrinsic dropped from the pool due to exceeding limits".into(),
+				})),
+			TransactionStatus::Invalid =>
+				EventOutcome::Ready(TransactionEvent::Invalid(TransactionError {
+					error: "Extrinsic marked as invalid".into(),
 				})),
-			TransactionStatus::Finalized((hash, index)) =>
-				Some(TransactionEvent::Finalized(TransactionBlock { hash, index })),
-			TransactionStatus::Usurped(_) => Some(TransactionEvent::Invalid(TransactionError {
-				error: "Extrinsic was rendered invalid by another extrinsic".into(),
-			})),
-			TransactionStatus::Dropped => Some(TransactionEvent::Invalid(TransactionError {
-				error: "Extrinsic dropped from the pool due to exceeding limits".into(),
-			})),
-			TransactionStatus::Invalid => Some(TransactionEvent::Invalid(TransactionError {
-				error: "Extrinsic marked as invalid".into(),
-			})),
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::testing::TaskExecutor;
+	use sp_runtime::transaction_validity::{InvalidTransaction, UnknownTransaction};
+
+	/// Extracts the code of the [`CallError::Custom`] a helper under test produced, panicking if
+	/// it's anything else.
+	fn call_error_code(err: CallError) -> i32 {
+		match err {
+			CallError::Custom(obj) => obj.code(),
+			other => panic!("expected a custom call error, got {:?}", other),
+		}
+	}
+
+	/// Extracts the code of the [`CallError::Custom`] wrapped in an [`RpcResult`] error,
+	/// panicking if the result is `Ok` or wraps anything else.
+	fn rpc_error_code<T: std::fmt::Debug>(result: RpcResult<T>) -> i32 {
+		match result {
+			Err(jsonrpsee::core::Error::Call(CallError::Custom(obj))) => obj.code(),
+			other => panic!("expected a custom call error, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn bad_format_error_uses_the_bad_format_code() {
+		assert_eq!(call_error_code(bad_format_error("truncated input")), BAD_FORMAT);
+	}
+
+	#[test]
+	fn runtime_error_uses_the_runtime_error_code() {
+		assert_eq!(call_error_code(runtime_error("execution trapped")), RUNTIME_ERROR);
+	}
+
+	#[test]
+	fn validated_transaction_response_maps_ok_to_a_validated_transaction() {
+		let valid = ValidTransaction {
+			priority: 5,
+			requires: vec![vec![1]],
+			provides: vec![vec![2]],
+			longevity: 64,
+			propagate: true,
+		};
+
+		assert_eq!(
+			validated_transaction_response(Ok(valid)).unwrap(),
+			ValidatedTransaction {
+				priority: 5,
+				requires: vec![Bytes(vec![1])],
+				provides: vec![Bytes(vec![2])],
+				longevity: 64,
+				propagate: true,
+			}
+		);
+	}
+
+	#[test]
+	fn validated_transaction_response_maps_invalid_to_the_invalid_transaction_code() {
+		let result = validated_transaction_response(Err(TransactionValidityError::Invalid(
+			InvalidTransaction::Custom(7),
+		)));
+
+		assert_eq!(rpc_error_code(result), INVALID_TRANSACTION);
+	}
+
+	#[test]
+	fn validated_transaction_response_maps_unknown_to_the_unknown_transaction_code() {
+		let result = validated_transaction_response(Err(TransactionValidityError::Unknown(
+			UnknownTransaction::Custom(3),
+		)));
+
+		assert_eq!(rpc_error_code(result), UNKNOWN_TRANSACTION);
+	}
+
+	/// An [`AbortHandle`] for a future that never resolves, good enough to exercise
+	/// [`BroadcastHandles`]' bookkeeping without a real broadcast task.
+	fn dummy_abort_handle() -> AbortHandle {
+		futures::future::abortable(futures::future::pending::<()>()).1
+	}
+
+	#[test]
+	fn stop_on_an_unknown_operation_id_is_an_error() {
+		let handles = BroadcastHandles::default();
+
+		let result = handles.stop("unknown");
+
+		assert_eq!(rpc_error_code(result), UNKNOWN_OPERATION_ID);
+	}
+
+	#[test]
+	fn stop_aborts_and_removes_a_registered_operation_id() {
+		let handles = BroadcastHandles::default();
+		let operation_id = handles.next_operation_id();
+		handles.insert(operation_id.clone(), dummy_abort_handle());
+
+		assert!(handles.stop(&operation_id).is_ok());
+		// The id was removed by the first `stop`, so a second one finds nothing to abort.
+		assert_eq!(rpc_error_code(handles.stop(&operation_id)), UNKNOWN_OPERATION_ID);
+	}
+
+	#[test]
+	fn remove_clears_a_completed_broadcast() {
+		let handles = BroadcastHandles::default();
+		let operation_id = handles.next_operation_id();
+		handles.insert(operation_id.clone(), dummy_abort_handle());
+
+		handles.remove(&operation_id);
+
+		// Already gone, as `broadcast_loop` leaves it once `broadcast_until_done` returns on
+		// its own.
+		assert_eq!(rpc_error_code(handles.stop(&operation_id)), UNKNOWN_OPERATION_ID);
+	}
+
+	#[test]
+	fn classify_broadcast_status_resubmits_on_usurped_and_dropped() {
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::Usurped(0)),
+			BroadcastStep::Resubmit
+		);
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::Dropped),
+			BroadcastStep::Resubmit
+		);
+	}
+
+	#[test]
+	fn classify_broadcast_status_stops_on_invalid() {
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::Invalid),
+			BroadcastStep::Stop
+		);
+	}
+
+	#[test]
+	fn classify_broadcast_status_continues_on_everything_else() {
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::Ready),
+			BroadcastStep::Continue
+		);
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::InBlock((0, 0))),
+			BroadcastStep::Continue
+		);
+		assert_eq!(
+			classify_broadcast_status(&TransactionStatus::<u8, u8>::Finalized((0, 0))),
+			BroadcastStep::Continue
+		);
+	}
+
+	struct FixedOutcome(DispatchOutcome);
+
+	impl ExtrinsicDispatchOutcome<u8> for FixedOutcome {
+		fn extrinsic_dispatch_outcome(&self, _at: u8, _index: u32) -> Option<DispatchOutcome> {
+			Some(self.0.clone())
+		}
+	}
+
+	#[tokio::test]
+	async fn resolve_dispatch_outcome_runs_registered_provider() {
+		let executor: SubscriptionTaskExecutor = Arc::new(TaskExecutor::new());
+		let outcome = DispatchOutcome { success: false, error: Some(Bytes(vec![1, 2, 3])) };
+		let provider: Arc<dyn ExtrinsicDispatchOutcome<u8> + Send + Sync> =
+			Arc::new(FixedOutcome(outcome.clone()));
+
+		let resolved = resolve_dispatch_outcome(&executor, Some(provider), 7u8, 3).await;
+
+		assert_eq!(resolved, Some(outcome));
+	}
+
+	#[tokio::test]
+	async fn resolve_dispatch_outcome_without_a_provider_is_none() {
+		let executor: SubscriptionTaskExecutor = Arc::new(TaskExecutor::new());
+
+		let resolved = resolve_dispatch_outcome(&executor, None, 7u8, 3).await;
+
+		assert_eq!(resolved, None);
+	}
+
+	#[tokio::test]
+	async fn included_event_populates_dispatch_outcome_from_the_registered_provider() {
+		let executor: SubscriptionTaskExecutor = Arc::new(TaskExecutor::new());
+		let outcome = DispatchOutcome { success: true, error: None };
+		let provider: Arc<dyn ExtrinsicDispatchOutcome<u8> + Send + Sync> =
+			Arc::new(FixedOutcome(outcome.clone()));
+
+		let event = included_event(&executor, Some(provider), 7u8, 3, false).await;
+
+		assert_eq!(
+			event,
+			TransactionEvent::BestChainBlockIncluded(Some(TransactionBlock {
+				hash: 7u8,
+				index: 3,
+				dispatch_outcome: Some(outcome),
+			}))
+		);
+	}
+
+	#[test]
+	fn repeated_ready_future_collapse_into_a_single_validated() {
+		let mut state = TransactionState::new();
+
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Ready),
+			EventOutcome::Ready(TransactionEvent::Validated)
+		);
+		assert_eq!(state.handle_event::<u8, u8>(TransactionStatus::Future), EventOutcome::Skip);
+		assert_eq!(state.handle_event::<u8, u8>(TransactionStatus::Ready), EventOutcome::Skip);
+	}
+
+	#[test]
+	fn broadcast_only_re_emits_on_a_strictly_larger_peer_count() {
+		let mut state = TransactionState::new();
+
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Broadcast(vec!["a".into()])),
+			EventOutcome::Ready(TransactionEvent::Broadcasted(TransactionBroadcasted {
+				num_peers: 1
+			}))
+		);
+		// Same peer count again (even with different peers) is coalesced away.
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Broadcast(vec!["b".into()])),
+			EventOutcome::Skip
+		);
+		// Fewer peers than previously reported is also coalesced away.
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Broadcast(vec![])),
+			EventOutcome::Skip
+		);
+		// Strictly more peers than last reported is forwarded.
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Broadcast(vec![
+				"a".into(),
+				"b".into()
+			])),
+			EventOutcome::Ready(TransactionEvent::Broadcasted(TransactionBroadcasted {
+				num_peers: 2
+			}))
+		);
+	}
+
+	#[test]
+	fn state_changing_transitions_are_always_forwarded_regardless_of_last_event() {
+		let mut state = TransactionState::new();
+		// Put the state machine into the "last emitted Validated" mode that would otherwise
+		// coalesce a subsequent Ready/Future.
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Ready),
+			EventOutcome::Ready(TransactionEvent::Validated)
+		);
+
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::InBlock((7u8, 3))),
+			EventOutcome::PendingDispatchOutcome { hash: 7u8, index: 3, finalized: false }
+		);
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Retracted(7u8)),
+			EventOutcome::Ready(TransactionEvent::BestChainBlockIncluded(None))
+		);
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Finalized((7u8, 3))),
+			EventOutcome::PendingDispatchOutcome { hash: 7u8, index: 3, finalized: true }
+		);
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Invalid),
+			EventOutcome::Ready(TransactionEvent::Invalid(TransactionError {
+				error: "Extrinsic marked as invalid".into(),
+			}))
+		);
+		assert_eq!(
+			state.handle_event::<u8, u8>(TransactionStatus::Dropped),
+			EventOutcome::Ready(TransactionEvent::Invalid(TransactionError {
+				error: "Extrinsic dropped from the pool due to exceeding limits".into(),
+			}))
+		);
+	}
+}